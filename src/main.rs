@@ -1,33 +1,46 @@
 extern crate rand;
+extern crate rayon;
 
 use std::cmp::min;
 use std::cmp::max;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use rayon::prelude::*;
 
-/// A graph contains 64 nodes which represent squares on the chess board.
+/// A graph contains `n * n` nodes which represent squares on an `n`x`n` chess board.
 ///
 /// Each node is connected to between 2 and 8 others via edges which represent valid moves made by
 /// a knight (1 square in one direction followed by 2 squares in another or vice versa).
 ///
 /// Ants traverse this graph in an attempt to find a valid knight's tour. Pheromone is layed along
 /// each edge so that subsequent ants can learn from those who came before.
+///
+/// Square indices are stored as `i8` (see `Edge::target`, `Ant::start`/`current`), so `n * n` must
+/// fit in an `i8`: boards up to `11x11` are supported. On boards smaller than `4x4` some squares
+/// have fewer than 2 moves (the centre of a `3x3` board has none).
 struct Graph {
+    n: i8,
     nodes: Vec<Node>,
 }
 
 impl Graph {
-    fn new(initial_pheromone: f32) -> Self {
-        let mut nodes = Vec::with_capacity(64);
+    fn new(n: i8, initial_pheromone: f32) -> Self {
+        assert!(n > 0 && (n as i32) * (n as i32) <= i8::MAX as i32,
+                "Graph::new: n = {} is out of range; n * n must fit in an i8 (n up to 11)", n);
+
+        let num_squares = (n * n) as usize;
+        let mut nodes = Vec::with_capacity(num_squares);
 
-        for i in 0..64 {
-            let mut node = Node::new(i);
+        for i in 0..n * n {
+            let mut node = Node::new(i, n);
 
             // Find the minimum square of nodes which contain all of the possible moves from
             // the current node. A knight can only move 2 squares in any direction so there is
             // no point searching for moves beyond that boundary.
             let min_x = max(0, node.x - 2);
-            let max_x = min(7, node.x + 2);
+            let max_x = min(n - 1, node.x + 2);
             let min_y = max(0, node.y - 2);
-            let max_y = min(7, node.y + 2);
+            let max_y = min(n - 1, node.y + 2);
 
             for x in min_x..max_x + 1 {
                 for y in min_y..max_y + 1 {
@@ -35,7 +48,7 @@ impl Graph {
                     // A knight's move is two sides of a right-angled triangle where a = 1 and b = 2.
                     // This means that c must be 1^2 + 2^2 = 1 + 4 = 5 to form a valid move.
                     if 5 == ((node.x - x).pow(2) + (node.y - y).pow(2)) {
-                        let edge = Edge::new(initial_pheromone, y * 8 + x);
+                        let edge = Edge::new(initial_pheromone, y * n + x);
                         node.edges.push(edge);
                     }
                 }
@@ -44,7 +57,7 @@ impl Graph {
             nodes.push(node);
         }
 
-        Graph {nodes: nodes}
+        Graph {n: n, nodes: nodes}
     }
 
     fn node(&self, index: &i8) -> &Node {
@@ -66,6 +79,18 @@ impl Graph {
             }
         }
     }
+
+    /// Clamps every edge's pheromone level into `[tau_min, tau_max]`.
+    ///
+    /// Used by MAX-MIN Ant System to prevent any single edge from dominating so early that the
+    /// colony stagnates on one path.
+    fn clamp_pheromones(&mut self, tau_min: f32, tau_max: f32) {
+        for node in &mut self.nodes {
+            for edge in &mut node.edges {
+                edge.pheromone = edge.pheromone.max(tau_min).min(tau_max);
+            }
+        }
+    }
 }
 
 /// A single node in the graph representing a square on the chess board.
@@ -76,8 +101,8 @@ struct Node {
 }
 
 impl Node {
-    fn new(index: i8) -> Self {
-        Node {x: index % 8, y: index / 8, edges: Vec::with_capacity(8)}
+    fn new(index: i8, n: i8) -> Self {
+        Node {x: index % n, y: index / n, edges: Vec::with_capacity(8)}
     }
 
     fn edge(&self, index: &i8) -> &Edge {
@@ -103,25 +128,57 @@ impl Edge {
     }
 }
 
+/// Ant Colony System tuning parameters used by `Ant::tour_acs`.
+struct AcsParams {
+    /// Exploitation probability: with this chance an ant deterministically picks the strongest
+    /// candidate edge instead of rolling the roulette wheel.
+    q0: f32,
+    /// Local pheromone decay applied to an edge the moment an ant crosses it.
+    xi: f32,
+    /// The initial pheromone level edges are decayed towards by the local update.
+    tau0: f32,
+}
+
 /// Ants traverse the graph in an attempt to find knight's tours.
 struct Ant {
     start: i8,
     current: i8,
     tabu: Vec<i8>,
     moves: Vec<i8>,
+    /// This ant's own RNG stream, seeded independently so that tours run concurrently are both
+    /// reproducible and free of contention on a shared generator.
+    rng: StdRng,
 }
 
 impl Ant {
-    fn new(start: i8) -> Self {
-        let mut tabu = Vec::with_capacity(64);
+    fn new(start: i8, seed: u64, n: i8) -> Self {
+        let num_squares = (n * n) as usize;
+        let mut tabu = Vec::with_capacity(num_squares);
 
         tabu.push(start);
 
-        Ant {start: start, current: start, tabu: tabu, moves: Vec::with_capacity(64)}
+        Ant {start: start, current: start, tabu: tabu, moves: Vec::with_capacity(num_squares), rng: StdRng::seed_from_u64(seed)}
+    }
+
+    /// Returns `true` if `moves` forms a complete tour of an `n`x`n` board: every square visited
+    /// exactly once. If `require_closed` is set, the final square must also have a knight's-move
+    /// edge back to `start`, making this a closed (re-entrant) tour.
+    fn is_complete(&self, graph: &Graph, require_closed: bool) -> bool {
+        let n = graph.n;
+
+        if self.moves.len() != (n * n - 1) as usize {
+            return false;
+        }
+
+        if require_closed {
+            return graph.node(&self.current).edges.iter().any(|edge| edge.target == self.start);
+        }
+
+        true
     }
 
-    fn tour(&mut self, graph: &Graph) -> bool {
-        let pheromone_strength_exponent: f32 = 1.0;
+    fn tour(&mut self, graph: &Graph, alpha: f32, beta: f32, require_closed: bool, repair_budget: u32) -> bool {
+        let n = graph.n;
 
         loop {
 
@@ -130,13 +187,18 @@ impl Ant {
             let mut pk_sum: f32 = 0.0;
 
             // Check each edge to see if it is an available mode (we have not followed it before).
-            // If it is, calculate its pheromone strenth with which to weight the probability of
-            // following this edge versus others.
+            // If it is, weight it by pheromone strength raised to alpha combined with a Warnsdorff
+            // desirability term raised to beta so that ants favour squares with fewer onward moves.
             for (k, edge) in current_node.edges.iter().enumerate() {
                 if !self.tabu.contains(&edge.target) {
-                    let pheromone_strength = edge.pheromone.powf(pheromone_strength_exponent);
-                    pk_sum += pheromone_strength;
-                    pks.push((k as i8, pheromone_strength));
+                    let target_node = graph.node(&edge.target);
+                    let degree = target_node.edges.iter()
+                        .filter(|e| !self.tabu.contains(&e.target))
+                        .count();
+                    let eta = 1.0 / (degree as f32 + 1.0);
+                    let weight = edge.pheromone.powf(alpha) * eta.powf(beta);
+                    pk_sum += weight;
+                    pks.push((k as i8, weight));
                 }
             }
 
@@ -148,7 +210,7 @@ impl Ant {
             // Calculate the probability of choosing each edge k based on the pheromone level Pk.
             let ps = pks.iter().map(|&pk| (pk.0, pk.1 / pk_sum)).collect::<Vec<_>>();
 
-            let mut x = rand::random::<f32>();
+            let mut x = self.rng.gen::<f32>();
             let mut k = 0;
 
             // FIXME: Why can I not use "for (mv, p) in &ps" here?
@@ -172,42 +234,301 @@ impl Ant {
             self.moves.push(k);
         }
 
-        self.moves.len() == 63
+        if repair_budget > 0 && self.moves.len() < (n * n - 1) as usize {
+            self.backtrack_repair(graph, alpha, beta, repair_budget);
+        }
+
+        self.is_complete(graph, require_closed)
+    }
+
+    /// Budget-limited backtracking repair for an ant whose constructive walk got stuck with
+    /// squares still unvisited. Pops the last move, forbids the square that turned out to be a
+    /// dead end for that branch, and retries with the next-best candidate from the predecessor,
+    /// climbing back further whenever a predecessor runs out of alternatives too. Gives up once
+    /// `budget` node expansions have been spent. `tabu` and `moves` are kept in lock-step on
+    /// every pop so the path stays valid for `lay_pheromone` even if repair ultimately fails.
+    fn backtrack_repair(&mut self, graph: &Graph, alpha: f32, beta: f32, budget: u32) {
+        let n = graph.n;
+        let target_moves = (n * n - 1) as usize;
+        let mut forbidden: Vec<Vec<i8>> = vec![Vec::new(); target_moves];
+        let mut expansions = 0u32;
+
+        while self.moves.len() < target_moves {
+            if expansions >= budget {
+                return;
+            }
+            expansions += 1;
+
+            let depth = self.moves.len();
+            let current_node = graph.node(&self.current);
+            let mut candidates = Vec::with_capacity(8);
+
+            for (k, edge) in current_node.edges.iter().enumerate() {
+                if !self.tabu.contains(&edge.target) && !forbidden[depth].contains(&edge.target) {
+                    let target_node = graph.node(&edge.target);
+                    let degree = target_node.edges.iter()
+                        .filter(|e| !self.tabu.contains(&e.target))
+                        .count();
+                    let eta = 1.0 / (degree as f32 + 1.0);
+                    let weight = edge.pheromone.powf(alpha) * eta.powf(beta);
+                    candidates.push((k as i8, weight));
+                }
+            }
+
+            if candidates.is_empty() {
+                // Dead end: back up to the predecessor and forbid the square we retreated from,
+                // so it is not immediately retried.
+                if self.moves.is_empty() {
+                    return;
+                }
+
+                let dead_end = self.current;
+
+                self.moves.pop();
+                self.tabu.pop();
+                self.current = *self.tabu.last().unwrap();
+
+                forbidden[depth].clear();
+                forbidden[depth - 1].push(dead_end);
+
+                continue;
+            }
+
+            // Take the strongest remaining candidate: repair is a focused local search, not a
+            // re-run of the probabilistic walk that got stuck in the first place.
+            let mut best = candidates[0];
+            for &c in &candidates[1..] {
+                if c.1 > best.1 {
+                    best = c;
+                }
+            }
+
+            let next = current_node.edge(&best.0).target;
+
+            self.current = next;
+            self.tabu.push(self.current);
+            self.moves.push(best.0);
+        }
+    }
+
+    /// Ant Colony System variant of `tour`.
+    ///
+    /// Candidate edges are weighted exactly as in `tour`, but the move is chosen with the
+    /// pseudo-random-proportional rule (exploit the strongest candidate with probability `q0`,
+    /// otherwise fall back to roulette-wheel selection), and a local pheromone update is applied
+    /// to the edge crossed so that other ants in this cycle are nudged away from following the
+    /// same path. This requires mutable access to the graph mid-tour, unlike `tour`.
+    fn tour_acs(&mut self, graph: &mut Graph, alpha: f32, beta: f32, acs: &AcsParams, require_closed: bool) -> bool {
+        let q0 = acs.q0;
+        let xi = acs.xi;
+        let tau0 = acs.tau0;
+
+        loop {
+
+            let from = self.current;
+            let mut candidates = Vec::with_capacity(8);
+
+            {
+                let current_node = graph.node(&from);
+
+                for (k, edge) in current_node.edges.iter().enumerate() {
+                    if !self.tabu.contains(&edge.target) {
+                        let target_node = graph.node(&edge.target);
+                        let degree = target_node.edges.iter()
+                            .filter(|e| !self.tabu.contains(&e.target))
+                            .count();
+                        let eta = 1.0 / (degree as f32 + 1.0);
+                        let weight = edge.pheromone.powf(alpha) * eta.powf(beta);
+                        candidates.push((k as i8, weight));
+                    }
+                }
+            }
+
+            // If there are no candidates then there are no more edges to try.
+            if candidates.is_empty() {
+                break;
+            }
+
+            let q = self.rng.gen::<f32>();
+
+            let k = if q <= q0 {
+                // Exploitation: deterministically take the strongest candidate.
+                let mut best = candidates[0];
+                for &c in &candidates[1..] {
+                    if c.1 > best.1 {
+                        best = c;
+                    }
+                }
+                best.0
+            } else {
+                // Exploration: roulette-wheel selection, as in the plain Ant System.
+                let weight_sum: f32 = candidates.iter().map(|&(_, w)| w).sum();
+                let mut x = self.rng.gen::<f32>() * weight_sum;
+                let mut chosen = candidates[candidates.len() - 1].0;
+
+                for &(ck, w) in &candidates {
+                    x -= w;
+                    if x <= 0.0 {
+                        chosen = ck;
+                        break;
+                    }
+                }
+
+                chosen
+            };
+
+            let next = graph.node(&from).edge(&k).target;
+
+            // Move to the new node.
+            self.current = next;
+
+            // Prevent visiting the current node again.
+            self.tabu.push(self.current);
+
+            // Record the move.
+            self.moves.push(k);
+
+            // Local pheromone update: decay the edge we just crossed towards tau0.
+            let edge = graph.node_mut(&from).edge_mut(&k);
+            edge.pheromone = (1.0 - xi) * edge.pheromone + xi * tau0;
+        }
+
+        self.is_complete(graph, require_closed)
     }
 
     fn lay_pheromone(&self, graph: &mut Graph) {
+        let n = graph.n;
         let pheromone_update_rate: f32 = 1.0;
         let num_moves = self.moves.len();
+        let max_moves = (n * n - 1) as usize;
         let mut current = self.start;
 
         for (i, k) in self.moves.iter().enumerate() {
 
-            let delta_pheromone = pheromone_update_rate * ((num_moves - i) as f32 / (63 - i) as f32);
+            let delta_pheromone = pheromone_update_rate * ((num_moves - i) as f32 / (max_moves - i) as f32);
             let edge = graph.node_mut(&current).edge_mut(k);
 
             edge.pheromone += delta_pheromone;
             current = edge.target;
         }
     }
+
+    /// Reconstructs the sequence of square indices this ant has visited so far, by walking
+    /// `start` through `moves` via `Edge::target`.
+    fn path(&self, graph: &Graph) -> Vec<i8> {
+        let mut path = Vec::with_capacity(self.moves.len() + 1);
+        let mut current = self.start;
+
+        path.push(current);
+
+        for k in &self.moves {
+            let edge = graph.node(&current).edge(k);
+            current = edge.target;
+            path.push(current);
+        }
+
+        path
+    }
+}
+
+/// Selects which Ant System pheromone-update strategy a `TourFinder` uses.
+enum AntSystemVariant {
+    /// The original unbounded behaviour: every ant deposits pheromone every cycle.
+    AntSystem,
+    /// MAX-MIN Ant System: only the iteration-best ant deposits, and pheromone is clamped into
+    /// `[tau_min, tau_max]` after every update to avoid premature stagnation.
+    MaxMin,
+    /// Ant Colony System: ants choose moves via the pseudo-random-proportional rule and apply a
+    /// local pheromone update as they cross each edge, diversifying ants within a cycle.
+    AntColonySystem,
 }
 
 struct TourFinder {
     graph: Graph,
+    /// The board dimension: the board has `n * n` squares.
+    n: i8,
+    /// Whether a tour only counts as complete if its final square has a knight's-move edge back
+    /// to its start, making this a closed (re-entrant) tour.
+    require_closed: bool,
     complete: u32,
     incomplete: u32,
-    p_evap_rate: f32
+    p_evap_rate: f32,
+    /// Exponent applied to pheromone strength when weighting candidate edges.
+    alpha: f32,
+    /// Exponent applied to the Warnsdorff heuristic desirability when weighting candidate edges.
+    beta: f32,
+    variant: AntSystemVariant,
+    tau_min: f32,
+    tau_max: f32,
+    /// MAX-MIN Ant System only: the length (in moves) of the longest tour found so far, starting
+    /// at 1.0; `n * n - 1` once a complete tour is found. Not updated in the other modes.
+    best_cost: f32,
+    /// The initial pheromone level passed to `TourFinder::new` (ACS's tau0).
+    initial_pheromone: f32,
+    /// ACS exploitation probability: with this chance an ant deterministically picks the
+    /// strongest candidate edge instead of rolling the roulette wheel.
+    q0: f32,
+    /// ACS local pheromone decay applied to an edge the moment an ant crosses it.
+    xi: f32,
+    /// Number of cycles run so far, used to seed each ant's RNG reproducibly.
+    cycle_count: u64,
+    /// The longest tour found so far, as a sequence of square indices (0 to `n * n - 1`). A
+    /// complete tour has `n * n` entries; shorter sequences are the best partial tour found when
+    /// none has completed.
+    best_tour: Option<Vec<i8>>,
+    /// Maximum number of node expansions `Ant::tour`'s backtracking repair may spend rescuing a
+    /// stuck ant. Zero disables repair.
+    repair_budget: u32,
 }
 
 impl TourFinder {
-    fn new(p_initial_level: f32, p_evap_rate: f32) -> Self {
+    fn new(n: i8, p_initial_level: f32, p_evap_rate: f32, require_closed: bool) -> Self {
         TourFinder {
-            graph: Graph::new(p_initial_level),
+            graph: Graph::new(n, p_initial_level),
+            n: n,
+            require_closed: require_closed,
             complete: 0,
             incomplete: 0,
-            p_evap_rate: p_evap_rate
+            p_evap_rate: p_evap_rate,
+            alpha: 1.0,
+            beta: 2.0,
+            variant: AntSystemVariant::AntSystem,
+            tau_min: 0.0,
+            tau_max: 0.0,
+            best_cost: 1.0,
+            initial_pheromone: p_initial_level,
+            q0: 0.9,
+            xi: 0.1,
+            cycle_count: 0,
+            best_tour: None,
+            repair_budget: 0,
         }
     }
 
+    /// Switches this `TourFinder` into MAX-MIN Ant System mode, re-initialising every edge's
+    /// pheromone to `tau_max` as MMAS requires rather than the tiny flat level `AntSystem` mode
+    /// starts with.
+    fn with_max_min(mut self) -> Self {
+        self.tau_max = 1.0 / (self.p_evap_rate * self.best_cost);
+        self.tau_min = self.tau_max / (2.0 * (self.n as f32 * self.n as f32));
+        self.graph = Graph::new(self.n, self.tau_max);
+        self.variant = AntSystemVariant::MaxMin;
+        self
+    }
+
+    /// Switches this `TourFinder` into Ant Colony System mode.
+    fn with_acs(mut self) -> Self {
+        self.variant = AntSystemVariant::AntColonySystem;
+        self
+    }
+
+    /// Enables backtracking repair for stuck ants, spending up to `budget` node expansions per
+    /// ant trying to rescue an otherwise-incomplete tour.
+    fn with_repair_budget(mut self, budget: u32) -> Self {
+        self.repair_budget = budget;
+        self
+    }
+
     fn run(&mut self, cycles: u32) {
         for _ in 0..cycles {
             self.cycle()
@@ -215,17 +536,48 @@ impl TourFinder {
     }
 
     fn cycle(&mut self) {
-        // Place an ant on each node.
-        let mut ants = Vec::with_capacity(64);
+        // Place an ant on each node. Each ant is seeded from the cycle and its own index so that
+        // runs are reproducible regardless of how the tours below are scheduled across threads.
+        let cycle_index = self.cycle_count;
+        self.cycle_count += 1;
+
+        let n = self.n;
+        let num_squares = (n * n) as u64;
+
+        let mut ants: Vec<Ant> = (0..n * n)
+            .map(|i| Ant::new(i, cycle_index * num_squares + i as u64, n))
+            .collect();
+
+        let alpha = self.alpha;
+        let beta = self.beta;
+        let require_closed = self.require_closed;
+        let repair_budget = self.repair_budget;
+
+        // Have each ant attempt a tour. Pheromones are not laid until all ants have finished, so
+        // the tours themselves (which only read the graph, except in ACS mode) can run in
+        // parallel across a thread pool.
+        let results: Vec<bool> = match self.variant {
+            AntSystemVariant::AntColonySystem => {
+                // ACS's local update mutates the graph mid-tour, which rules out running these
+                // tours concurrently, so they run sequentially in this mode.
+                let acs = AcsParams {q0: self.q0, xi: self.xi, tau0: self.initial_pheromone};
+                let graph = &mut self.graph;
+
+                ants.iter_mut()
+                    .map(|ant| ant.tour_acs(graph, alpha, beta, &acs, require_closed))
+                    .collect()
+            }
+            _ => {
+                let graph = &self.graph;
 
-        for i in 0..64 {
-            ants.push(Ant::new(i));
-        }
+                ants.par_iter_mut()
+                    .map(|ant| ant.tour(graph, alpha, beta, require_closed, repair_budget))
+                    .collect()
+            }
+        };
 
-        // Have each ant attempt a tour.
-        // TODO: Do this concurrently - pheromones are not laid until all ants have finished.
-        for ant in &mut ants {
-            if ant.tour(&self.graph) {
+        for completed in results {
+            if completed {
                 self.complete += 1;
             } else {
                 self.incomplete += 1;
@@ -233,20 +585,93 @@ impl TourFinder {
         }
 
         // Now all ants have finished an attempt, have them lay pheromones.
-        for ant in &ants {
-            ant.lay_pheromone(&mut self.graph);
+        match self.variant {
+            AntSystemVariant::AntSystem | AntSystemVariant::AntColonySystem => {
+                for ant in &ants {
+                    ant.lay_pheromone(&mut self.graph);
+                }
+            }
+            AntSystemVariant::MaxMin => {
+                // Only the iteration-best ant deposits, found by longest tour (a completed tour,
+                // if any exists, is always at least as long as any incomplete one).
+                let best = ants.iter().max_by_key(|ant| ant.moves.len()).unwrap();
+                best.lay_pheromone(&mut self.graph);
+
+                let best_len = best.moves.len() as f32;
+                if best_len > self.best_cost {
+                    self.best_cost = best_len;
+                }
+            }
         }
 
         // Evapourate pheromones so that weak routes are forgotten over time.
         self.graph.evaporate_pheromones(&self.p_evap_rate);
+
+        if let AntSystemVariant::MaxMin = self.variant {
+            self.tau_max = 1.0 / (self.p_evap_rate * self.best_cost);
+            self.tau_min = self.tau_max / (2.0 * num_squares as f32);
+            self.graph.clamp_pheromones(self.tau_min, self.tau_max);
+        }
+
+        // Keep the longest tour found so far, so that even a short run produces a useful path.
+        let longest = ants.iter().max_by_key(|ant| ant.moves.len()).unwrap();
+        let is_longer = match &self.best_tour {
+            Some(existing) => longest.moves.len() + 1 > existing.len(),
+            None => true,
+        };
+
+        if is_longer {
+            self.best_tour = Some(longest.path(&self.graph));
+        }
+    }
+
+    /// Returns the best tour found so far as a sequence of square indices (0 to `n * n - 1`),
+    /// one per square visited in order. Empty if no cycle has been run yet.
+    fn best_tour(&self) -> Vec<i8> {
+        self.best_tour.clone().unwrap_or_default()
+    }
+
+    /// Renders `best_tour` as an `n`x`n` board of move-order numbers, one cell per square, for
+    /// printing. Squares not yet visited in that tour are shown as `.`.
+    fn render_best_tour(&self) -> String {
+        let n = self.n as usize;
+        let mut board = vec![None; n * n];
+
+        if let Some(tour) = &self.best_tour {
+            for (order, &square) in tour.iter().enumerate() {
+                board[square as usize] = Some(order + 1);
+            }
+        }
+
+        let mut rendered = String::new();
+
+        for y in 0..n {
+            for x in 0..n {
+                match board[y * n + x] {
+                    Some(order) => rendered.push_str(&format!("{:3} ", order)),
+                    None => rendered.push_str("  . "),
+                }
+            }
+            rendered.push('\n');
+        }
+
+        rendered
     }
 }
 
 
 fn main() {
-    let mut tour_finder = TourFinder::new(0.000001, 0.25);
+    run_to_completion("Ant System", TourFinder::new(8, 0.000001, 0.25, false));
+    run_to_completion("MAX-MIN Ant System", TourFinder::new(8, 0.000001, 0.25, false).with_max_min());
+    run_to_completion("Ant Colony System", TourFinder::new(8, 0.000001, 0.25, false).with_acs());
+    run_to_completion("Ant System with backtracking repair", TourFinder::new(8, 0.000001, 0.25, false).with_repair_budget(200));
+}
 
+/// Runs `tour_finder` for a fixed number of cycles and prints a summary labelled `name`.
+fn run_to_completion(name: &str, mut tour_finder: TourFinder) {
     tour_finder.run(10000);
 
+    println!("== {} ==", name);
     println!("Complete: {}, Incomplete: {}", tour_finder.complete, tour_finder.incomplete);
+    println!("Best tour ({} squares):\n{}", tour_finder.best_tour().len(), tour_finder.render_best_tour());
 }